@@ -8,7 +8,11 @@ pub mod simple_types {
 
     lazy_static! {
         static ref EMAIL_RE: Regex = Regex::new(r".+@.+").unwrap();
+        static ref WIDGET_CODE_RE: Regex = Regex::new(r"^W\d{4}$").unwrap();
+        static ref GIZMO_CODE_RE: Regex = Regex::new(r"^G\d{3}$").unwrap();
+        static ref ZIP_CODE_RE: Regex = Regex::new(r"^\d{5}$").unwrap();
     }
+    #[derive(Clone)]
     pub struct String50(String);
 
     impl String50 {
@@ -18,20 +22,193 @@ pub mod simple_types {
             }
             Ok(Self(string50))
         }
+
+        pub fn value(&self) -> &str {
+            &self.0
+        }
     }
+    #[derive(Clone)]
     pub struct EmailAddress(String);
 
     impl EmailAddress {
         pub fn create(email: String) -> Result<Self> {
-            if EMAIL_RE.is_match(&email) {
+            if !EMAIL_RE.is_match(&email) {
                 return Err(anyhow!("Must have @ separator"));
             }
             Ok(Self(email))
         }
     }
+
+    // A widget code starts with a 'W' and is followed by four digits, e.g. "W1234".
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct WidgetCode(String);
+
+    impl WidgetCode {
+        pub fn create(code: String) -> Result<Self> {
+            if !WIDGET_CODE_RE.is_match(&code) {
+                return Err(anyhow!("WidgetCode must match pattern W9999"));
+            }
+            Ok(Self(code))
+        }
+    }
+
+    // A gizmo code starts with a 'G' and is followed by three digits, e.g. "G123".
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct GizmoCode(String);
+
+    impl GizmoCode {
+        pub fn create(code: String) -> Result<Self> {
+            if !GIZMO_CODE_RE.is_match(&code) {
+                return Err(anyhow!("GizmoCode must match pattern G999"));
+            }
+            Ok(Self(code))
+        }
+    }
+
+    // A US zip code, e.g. "90210".
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct ZipCode(String);
+
+    impl ZipCode {
+        pub fn create(zip_code: String) -> Result<Self> {
+            if !ZIP_CODE_RE.is_match(&zip_code) {
+                return Err(anyhow!("ZipCode must match pattern 99999"));
+            }
+            Ok(Self(zip_code))
+        }
+    }
+
+    // Quantity ordered in whole units, e.g. for widgets.
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct UnitQuantity(i64);
+
+    impl UnitQuantity {
+        pub fn create(quantity: i64) -> Result<Self> {
+            if !(1..=1000).contains(&quantity) {
+                return Err(anyhow!("UnitQuantity must be between 1 and 1000"));
+            }
+            Ok(Self(quantity))
+        }
+
+        pub fn value(&self) -> i64 {
+            self.0
+        }
+    }
+
+    // Quantity ordered by weight, e.g. for gizmos.
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct KilogramQuantity(f64);
+
+    impl KilogramQuantity {
+        pub fn create(quantity: f64) -> Result<Self> {
+            if !(0.05..=100.0).contains(&quantity) {
+                return Err(anyhow!("KilogramQuantity must be between 0.05 and 100.0"));
+            }
+            Ok(Self(quantity))
+        }
+
+        pub fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    // A price, either per unit of a product or for a whole order line, e.g. $19.99.
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct Price(f64);
+
+    impl Price {
+        pub fn create(price: f64) -> Result<Self> {
+            if !(0.0..=1000.0).contains(&price) {
+                return Err(anyhow!("Price must be between 0.0 and 1000.0"));
+            }
+            Ok(Self(price))
+        }
+
+        pub fn value(&self) -> f64 {
+            self.0
+        }
+    }
+
+    // Total amount to bill a customer for an order.
+    #[derive(Clone, Copy, PartialEq)]
+    pub struct BillingAmount(f64);
+
+    impl BillingAmount {
+        pub fn create(amount: f64) -> Result<Self> {
+            if !(0.0..=10000.0).contains(&amount) {
+                return Err(anyhow!("BillingAmount must be between 0.0 and 10000.0"));
+            }
+            Ok(Self(amount))
+        }
+
+        pub fn value(&self) -> f64 {
+            self.0
+        }
+
+        pub fn sum_prices(prices: &[Price]) -> Result<Self> {
+            let total = prices.iter().map(Price::value).sum();
+            Self::create(total)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn zip_code_rejects_wrong_length() {
+            assert!(ZipCode::create("9021".into()).is_err());
+            assert!(ZipCode::create("902100".into()).is_err());
+        }
+
+        #[test]
+        fn zip_code_rejects_non_digits() {
+            assert!(ZipCode::create("9021O".into()).is_err());
+        }
+
+        #[test]
+        fn zip_code_accepts_five_digits() {
+            assert!(ZipCode::create("90210".into()).is_ok());
+        }
+
+        #[test]
+        fn price_rejects_out_of_range_values() {
+            assert!(Price::create(-0.01).is_err());
+            assert!(Price::create(1000.01).is_err());
+        }
+
+        #[test]
+        fn price_accepts_in_range_values() {
+            assert!(Price::create(0.0).is_ok());
+            assert!(Price::create(1000.0).is_ok());
+        }
+
+        #[test]
+        fn billing_amount_rejects_out_of_range_values() {
+            assert!(BillingAmount::create(-0.01).is_err());
+            assert!(BillingAmount::create(10000.01).is_err());
+        }
+
+        #[test]
+        fn billing_amount_sums_line_prices() {
+            let prices = [Price::create(19.99).unwrap(), Price::create(5.0).unwrap()];
+            let total = BillingAmount::sum_prices(&prices).unwrap();
+            assert!((total.value() - 24.99).abs() < f64::EPSILON);
+        }
+
+        #[test]
+        fn billing_amount_rejects_sum_out_of_range() {
+            let prices: Vec<Price> = std::iter::repeat(Price::create(1000.0).unwrap())
+                .take(11)
+                .collect();
+            assert!(BillingAmount::sum_prices(&prices).is_err());
+        }
+    }
 }
 
 pub mod public_types {
+    use anyhow::{anyhow, Result};
+
     use crate::simple_types::*;
 
     pub struct UnvalidatedCustomerInfo {
@@ -54,45 +231,189 @@ pub mod public_types {
                 email_address,
             }
         }
+
+        pub fn full_name(&self) -> String {
+            format!("{} {}", self.first_name.value(), self.last_name.value())
+        }
     }
     pub struct UnvalidatedOrder {
         pub order_id: String,
+        pub customer_info: UnvalidatedCustomerInfo,
+        pub shipping_address: UnvalidatedAddress,
+        pub billing_address: UnvalidatedAddress,
         pub lines: Vec<UnvalidatedOrderLine>,
     }
     pub struct UnvalidatedOrderLine {
         pub order_line_id: String,
         pub product_code: String,
-        pub quantity: i64,
+        pub quantity: f64,
     }
-    pub struct Address {}
 
+    pub struct UnvalidatedAddress {
+        pub address_line_1: String,
+        pub address_line_2: Option<String>,
+        pub address_line_3: Option<String>,
+        pub address_line_4: Option<String>,
+        pub city: String,
+        pub zip_code: String,
+    }
+
+    // An address that has been checked against an external address-verification
+    // service, but whose fields are not yet known to satisfy our own constraints.
+    pub struct CheckedAddress {
+        pub address_line_1: String,
+        pub address_line_2: Option<String>,
+        pub address_line_3: Option<String>,
+        pub address_line_4: Option<String>,
+        pub city: String,
+        pub zip_code: String,
+    }
+
+    #[derive(Clone)]
+    pub struct Address {
+        address_line_1: String50,
+        address_line_2: Option<String50>,
+        address_line_3: Option<String50>,
+        address_line_4: Option<String50>,
+        city: String50,
+        zip_code: ZipCode,
+    }
+
+    impl Address {
+        pub fn new(
+            address_line_1: String50,
+            address_line_2: Option<String50>,
+            address_line_3: Option<String50>,
+            address_line_4: Option<String50>,
+            city: String50,
+            zip_code: ZipCode,
+        ) -> Self {
+            Self {
+                address_line_1,
+                address_line_2,
+                address_line_3,
+                address_line_4,
+                city,
+                zip_code,
+            }
+        }
+    }
+
+    #[derive(Clone)]
     pub struct OrderPlaced {}
-    pub struct BillableOrderPlaced {}
-    pub struct OrderAcknowledgmentSent {}
+
+    #[derive(Clone)]
+    pub struct BillableOrderPlaced {
+        pub order_id: OrderId,
+        pub customer_name: String,
+        pub billing_address: Address,
+        pub amount_to_bill: BillingAmount,
+    }
+
+    impl BillableOrderPlaced {
+        pub fn new(
+            order_id: OrderId,
+            customer_name: String,
+            billing_address: Address,
+            amount_to_bill: BillingAmount,
+        ) -> Self {
+            Self {
+                order_id,
+                customer_name,
+                billing_address,
+                amount_to_bill,
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct OrderAcknowledgmentSent {
+        pub order_id: OrderId,
+    }
+
+    impl OrderAcknowledgmentSent {
+        pub fn new(order_id: OrderId) -> Self {
+            Self { order_id }
+        }
+    }
 
     #[derive(Clone, PartialEq, Eq)]
-    pub struct ProductCode(String);
+    pub enum ProductCode {
+        Widget(WidgetCode),
+        Gizmo(GizmoCode),
+    }
 
     impl ProductCode {
-        pub fn new(code: String) -> Self {
-            Self(code)
+        pub fn create(code: String) -> Result<Self> {
+            match code.chars().next() {
+                Some('W') => Ok(ProductCode::Widget(WidgetCode::create(code)?)),
+                Some('G') => Ok(ProductCode::Gizmo(GizmoCode::create(code)?)),
+                _ => Err(anyhow!("ProductCode must start with W or G")),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ValidationError {
+        pub field_name: String,
+        pub message: String,
+    }
+
+    impl ValidationError {
+        pub fn new(field_name: impl Into<String>, message: impl Into<String>) -> Self {
+            Self {
+                field_name: field_name.into(),
+                message: message.into(),
+            }
+        }
+    }
+
+    // Rendered HTML body of an order-acknowledgment letter.
+    pub struct HtmlString(String);
+
+    impl HtmlString {
+        pub fn new(html: String) -> Self {
+            Self(html)
+        }
+
+        pub fn value(&self) -> &str {
+            &self.0
         }
     }
 
-    pub struct OrderAcknowledgment {}
+    pub enum SendResult {
+        Sent,
+        NotSent,
+    }
 
+    pub struct OrderAcknowledgment {
+        pub letter: HtmlString,
+    }
+
+    impl OrderAcknowledgment {
+        pub fn new(letter: HtmlString) -> Self {
+            Self { letter }
+        }
+    }
+
+    #[derive(Clone)]
     pub enum PlaceOrderEvent {
         OrderPlaced(OrderPlaced),
         BillableOrderPlaced(BillableOrderPlaced),
         OrderAcknowledgmentSent(OrderAcknowledgmentSent),
     }
 
+    #[derive(Clone)]
     pub struct OrderId(String);
 
     impl OrderId {
         pub fn new(id: String) -> Self {
             Self(id)
         }
+
+        pub fn value(&self) -> &str {
+            &self.0
+        }
     }
 
     pub struct OrderLineId(String);
@@ -102,20 +423,106 @@ pub mod public_types {
             Self(id)
         }
     }
-    pub struct OrderQuantity(i64);
+    pub enum OrderQuantity {
+        Unit(UnitQuantity),
+        Kilogram(KilogramQuantity),
+    }
 
     impl OrderQuantity {
-        pub fn new(quantity: i64) -> Self {
-            Self(quantity)
+        pub fn create(product_code: &ProductCode, quantity: f64) -> Result<Self> {
+            match product_code {
+                ProductCode::Widget(_) => {
+                    if quantity.fract() != 0.0 {
+                        return Err(anyhow!("UnitQuantity must be a whole number"));
+                    }
+                    Ok(OrderQuantity::Unit(UnitQuantity::create(quantity as i64)?))
+                }
+                ProductCode::Gizmo(_) => {
+                    Ok(OrderQuantity::Kilogram(KilogramQuantity::create(quantity)?))
+                }
+            }
+        }
+
+        pub fn value(&self) -> f64 {
+            match self {
+                OrderQuantity::Unit(quantity) => quantity.value() as f64,
+                OrderQuantity::Kilogram(quantity) => quantity.value(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn product_code_rejects_unknown_prefix() {
+            assert!(ProductCode::create("X1234".into()).is_err());
+        }
+
+        #[test]
+        fn product_code_accepts_widget_and_gizmo() {
+            assert!(ProductCode::create("W1234".into()).is_ok());
+            assert!(ProductCode::create("G123".into()).is_ok());
+        }
+
+        #[test]
+        fn order_quantity_rejects_fractional_widget_quantity() {
+            let widget = ProductCode::create("W1234".into()).unwrap();
+            assert!(OrderQuantity::create(&widget, 5.7).is_err());
+        }
+
+        #[test]
+        fn order_quantity_accepts_whole_widget_quantity() {
+            let widget = ProductCode::create("W1234".into()).unwrap();
+            assert!(OrderQuantity::create(&widget, 5.0).is_ok());
+        }
+
+        #[test]
+        fn order_quantity_rejects_out_of_range_widget_quantity() {
+            let widget = ProductCode::create("W1234".into()).unwrap();
+            assert!(OrderQuantity::create(&widget, 0.0).is_err());
+            assert!(OrderQuantity::create(&widget, 1001.0).is_err());
+        }
+
+        #[test]
+        fn order_quantity_rejects_out_of_range_gizmo_quantity() {
+            let gizmo = ProductCode::create("G123".into()).unwrap();
+            assert!(OrderQuantity::create(&gizmo, 0.01).is_err());
+            assert!(OrderQuantity::create(&gizmo, 100.01).is_err());
+        }
+
+        #[test]
+        fn order_quantity_accepts_in_range_gizmo_quantity() {
+            let gizmo = ProductCode::create("G123".into()).unwrap();
+            assert!(OrderQuantity::create(&gizmo, 2.5).is_ok());
         }
     }
 }
 
+// Shared fixtures used by unit tests across modules.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::public_types::Address;
+    use crate::simple_types::{String50, ZipCode};
+
+    pub fn fake_address() -> Address {
+        Address::new(
+            String50::create("1 Fake Street".into()).unwrap(),
+            None,
+            None,
+            None,
+            String50::create("Faketown".into()).unwrap(),
+            ZipCode::create("90210".into()).unwrap(),
+        )
+    }
+}
+
 pub mod implementation {
 
     use crate::{
         public_types::*,
-        simple_types::{EmailAddress, String50},
+        simple_types::{BillingAmount, EmailAddress, Price, String50, ZipCode},
     };
     use anyhow::Result;
 
@@ -141,7 +548,7 @@ pub mod implementation {
 
     pub struct ValidatedOrder {
         order_id: OrderId,
-        // CustomerInfo: CustomerInfo,
+        customer_info: ValidatedCustomerInfo,
         shipping_address: Address,
         billing_address: Address,
         lines: Vec<ValidatedOrderLine>,
@@ -150,12 +557,14 @@ pub mod implementation {
     impl ValidatedOrder {
         pub fn new(
             order_id: OrderId,
+            customer_info: ValidatedCustomerInfo,
             shipping_address: Address,
             billing_address: Address,
             lines: Vec<ValidatedOrderLine>,
         ) -> Self {
             Self {
                 order_id,
+                customer_info,
                 shipping_address,
                 billing_address,
                 lines,
@@ -163,6 +572,46 @@ pub mod implementation {
         }
     }
 
+    pub struct PricedOrderLine {
+        line: ValidatedOrderLine,
+        line_price: Price,
+    }
+
+    impl PricedOrderLine {
+        pub fn new(line: ValidatedOrderLine, line_price: Price) -> Self {
+            Self { line, line_price }
+        }
+    }
+
+    pub struct PricedOrder {
+        order_id: OrderId,
+        customer_info: ValidatedCustomerInfo,
+        shipping_address: Address,
+        billing_address: Address,
+        lines: Vec<PricedOrderLine>,
+        amount_to_bill: BillingAmount,
+    }
+
+    impl PricedOrder {
+        pub fn new(
+            order_id: OrderId,
+            customer_info: ValidatedCustomerInfo,
+            shipping_address: Address,
+            billing_address: Address,
+            lines: Vec<PricedOrderLine>,
+            amount_to_bill: BillingAmount,
+        ) -> Self {
+            Self {
+                order_id,
+                customer_info,
+                shipping_address,
+                billing_address,
+                lines,
+                amount_to_bill,
+            }
+        }
+    }
+
     // ======================================================
     // Section 2 : Implementation
     // ======================================================
@@ -173,67 +622,272 @@ pub mod implementation {
 
     fn to_customer_info(
         unvalidated_customer_info: UnvalidatedCustomerInfo,
-    ) -> Result<ValidatedCustomerInfo> {
-        let first_name = String50::create(unvalidated_customer_info.first_name)?;
-        let last_name = String50::create(unvalidated_customer_info.last_name)?;
-        let email_address = EmailAddress::create(unvalidated_customer_info.email_address)?;
+    ) -> Result<ValidatedCustomerInfo, Vec<ValidationError>> {
+        let first_name = String50::create(unvalidated_customer_info.first_name)
+            .map_err(|e| ValidationError::new("first_name", e.to_string()));
+        let last_name = String50::create(unvalidated_customer_info.last_name)
+            .map_err(|e| ValidationError::new("last_name", e.to_string()));
+        let email_address = EmailAddress::create(unvalidated_customer_info.email_address)
+            .map_err(|e| ValidationError::new("email_address", e.to_string()));
+
+        let errors: Vec<ValidationError> = [
+            first_name.as_ref().err(),
+            last_name.as_ref().err(),
+            email_address.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         Ok(ValidatedCustomerInfo::new(
-            first_name,
-            last_name,
-            email_address,
+            first_name.unwrap(),
+            last_name.unwrap(),
+            email_address.unwrap(),
         ))
     }
 
+    fn to_address(checked_address: CheckedAddress) -> Result<Address, Vec<ValidationError>> {
+        let address_line_1 = String50::create(checked_address.address_line_1)
+            .map_err(|e| ValidationError::new("address_line_1", e.to_string()));
+        let address_line_2 = checked_address
+            .address_line_2
+            .map(String50::create)
+            .transpose()
+            .map_err(|e| ValidationError::new("address_line_2", e.to_string()));
+        let address_line_3 = checked_address
+            .address_line_3
+            .map(String50::create)
+            .transpose()
+            .map_err(|e| ValidationError::new("address_line_3", e.to_string()));
+        let address_line_4 = checked_address
+            .address_line_4
+            .map(String50::create)
+            .transpose()
+            .map_err(|e| ValidationError::new("address_line_4", e.to_string()));
+        let city = String50::create(checked_address.city)
+            .map_err(|e| ValidationError::new("city", e.to_string()));
+        let zip_code = ZipCode::create(checked_address.zip_code)
+            .map_err(|e| ValidationError::new("zip_code", e.to_string()));
+
+        let errors: Vec<ValidationError> = [
+            address_line_1.as_ref().err(),
+            address_line_2.as_ref().err(),
+            address_line_3.as_ref().err(),
+            address_line_4.as_ref().err(),
+            city.as_ref().err(),
+            zip_code.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Address::new(
+            address_line_1.unwrap(),
+            address_line_2.unwrap(),
+            address_line_3.unwrap(),
+            address_line_4.unwrap(),
+            city.unwrap(),
+            zip_code.unwrap(),
+        ))
+    }
+
+    fn to_checked_address(
+        check_address_exists: impl Fn(UnvalidatedAddress) -> Result<CheckedAddress>,
+        unvalidated_address: UnvalidatedAddress,
+    ) -> Result<Address, Vec<ValidationError>> {
+        let checked_address = check_address_exists(unvalidated_address)
+            .map_err(|e| vec![ValidationError::new("address", e.to_string())])?;
+        to_address(checked_address)
+    }
+
     fn to_validated_order_line(
         check_product_exists: impl Fn(&ProductCode) -> Result<()>,
         unvalidated_order_line: &UnvalidatedOrderLine,
-    ) -> Result<ValidatedOrderLine> {
-        let product_code = ProductCode::new(unvalidated_order_line.product_code.clone());
-        check_product_exists(&product_code)?;
+    ) -> Result<ValidatedOrderLine, Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        let product_code = match ProductCode::create(unvalidated_order_line.product_code.clone())
+        {
+            Ok(product_code) => match check_product_exists(&product_code) {
+                Ok(()) => Some(product_code),
+                Err(e) => {
+                    errors.push(ValidationError::new("product_code", e.to_string()));
+                    None
+                }
+            },
+            Err(e) => {
+                errors.push(ValidationError::new("product_code", e.to_string()));
+                None
+            }
+        };
+
+        let quantity = product_code.as_ref().and_then(|product_code| {
+            match OrderQuantity::create(product_code, unvalidated_order_line.quantity) {
+                Ok(quantity) => Some(quantity),
+                Err(e) => {
+                    errors.push(ValidationError::new("quantity", e.to_string()));
+                    None
+                }
+            }
+        });
 
-        let order_line_id = OrderLineId::new(unvalidated_order_line.order_line_id.clone());
-        let quantity = OrderQuantity::new(unvalidated_order_line.quantity);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
 
         Ok(ValidatedOrderLine::new(
-            order_line_id,
-            product_code,
-            quantity,
+            OrderLineId::new(unvalidated_order_line.order_line_id.clone()),
+            product_code.unwrap(),
+            quantity.unwrap(),
         ))
     }
 
     fn validate_order(
         check_product_exists: impl Fn(&ProductCode) -> Result<()> + Clone + Copy,
+        check_address_exists: impl Fn(UnvalidatedAddress) -> Result<CheckedAddress> + Clone + Copy,
         unvalidated_order: UnvalidatedOrder,
-    ) -> Result<ValidatedOrder> {
+    ) -> Result<ValidatedOrder, Vec<ValidationError>> {
         let order_id = OrderId::new(unvalidated_order.order_id);
-        let shipping_address = Address {};
-        let billing_address = Address {};
-        let lines = unvalidated_order
+        let customer_info = to_customer_info(unvalidated_order.customer_info);
+        let shipping_address =
+            to_checked_address(check_address_exists, unvalidated_order.shipping_address);
+        let billing_address =
+            to_checked_address(check_address_exists, unvalidated_order.billing_address);
+
+        let (lines, line_errors): (Vec<_>, Vec<_>) = unvalidated_order
             .lines
             .iter()
             .map(|unvalidated_order_line| {
                 to_validated_order_line(check_product_exists, unvalidated_order_line)
             })
-            .collect::<Result<Vec<ValidatedOrderLine>>>()?;
+            .partition(Result::is_ok);
+
+        let mut errors = Vec::new();
+        if let Err(e) = &customer_info {
+            errors.extend(e.clone());
+        }
+        if let Err(e) = &shipping_address {
+            errors.extend(e.clone());
+        }
+        if let Err(e) = &billing_address {
+            errors.extend(e.clone());
+        }
+        for line_error in line_errors {
+            if let Err(e) = line_error {
+                errors.extend(e);
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let lines = lines.into_iter().map(Result::unwrap).collect();
 
         Ok(ValidatedOrder::new(
             order_id,
-            shipping_address,
-            billing_address,
+            customer_info.unwrap(),
+            shipping_address.unwrap(),
+            billing_address.unwrap(),
+            lines,
+        ))
+    }
+
+    // ---------------------------
+    // PriceOrder step
+    // ---------------------------
+
+    fn price_order(
+        get_product_price: impl Fn(&ProductCode) -> Price,
+        order: ValidatedOrder,
+    ) -> Result<PricedOrder> {
+        let lines = order
+            .lines
+            .into_iter()
+            .map(|line| {
+                let unit_price = get_product_price(&line.product_code);
+                let line_price = Price::create(unit_price.value() * line.quantity.value())?;
+                Ok(PricedOrderLine::new(line, line_price))
+            })
+            .collect::<Result<Vec<PricedOrderLine>>>()?;
+
+        let line_prices: Vec<Price> = lines.iter().map(|line| line.line_price).collect();
+        let amount_to_bill = BillingAmount::sum_prices(&line_prices)?;
+
+        Ok(PricedOrder::new(
+            order.order_id,
+            order.customer_info,
+            order.shipping_address,
+            order.billing_address,
             lines,
+            amount_to_bill,
         ))
     }
 
+    // ---------------------------
+    // AcknowledgeOrder step
+    // ---------------------------
+
+    fn acknowledge_order(
+        create_letter: impl Fn(&PricedOrder) -> HtmlString,
+        send: impl Fn(&OrderAcknowledgment) -> SendResult,
+        order: &PricedOrder,
+    ) -> Option<OrderAcknowledgmentSent> {
+        let letter = create_letter(order);
+        let acknowledgment = OrderAcknowledgment::new(letter);
+
+        match send(&acknowledgment) {
+            SendResult::Sent => Some(OrderAcknowledgmentSent::new(order.order_id.clone())),
+            SendResult::NotSent => None,
+        }
+    }
+
     pub fn place_order(
         check_product_exists: impl Fn(&ProductCode) -> Result<()> + Clone + Copy,
-        // check_address_exists: impl Fn(Address) -> Result<()>,
-        // get_product_price: impl Fn(ProductCode) -> Result<()>,
-        // create_order_acknowledgment_letter: impl Fn(ProductCode) -> Result<OrderAcknowledgment>,
-        // send_order_acknowledgment: impl Fn(OrderAcknowledgment) -> Result<()>,
-    ) -> impl Fn(UnvalidatedOrder) -> Result<PlaceOrderEvent> {
+        check_address_exists: impl Fn(UnvalidatedAddress) -> Result<CheckedAddress> + Clone + Copy,
+        get_product_price: impl Fn(&ProductCode) -> Price + Clone + Copy,
+        create_order_acknowledgment_letter: impl Fn(&PricedOrder) -> HtmlString + Clone + Copy,
+        send_order_acknowledgment: impl Fn(&OrderAcknowledgment) -> SendResult + Clone + Copy,
+    ) -> impl Fn(UnvalidatedOrder) -> Result<Vec<PlaceOrderEvent>, Vec<ValidationError>> {
         move |unvalidated_order| {
-            let validated_order = validate_order(check_product_exists, unvalidated_order)?;
-            Ok(PlaceOrderEvent::BillableOrderPlaced(BillableOrderPlaced {}))
+            let validated_order =
+                validate_order(check_product_exists, check_address_exists, unvalidated_order)?;
+            let priced_order = price_order(get_product_price, validated_order)
+                .map_err(|e| vec![ValidationError::new("price_order", e.to_string())])?;
+
+            let acknowledgment_sent = acknowledge_order(
+                create_order_acknowledgment_letter,
+                send_order_acknowledgment,
+                &priced_order,
+            );
+
+            let mut events = vec![PlaceOrderEvent::OrderPlaced(OrderPlaced {})];
+
+            if priced_order.amount_to_bill.value() > 0.0 {
+                let customer_name = priced_order.customer_info.full_name();
+                events.push(PlaceOrderEvent::BillableOrderPlaced(BillableOrderPlaced::new(
+                    priced_order.order_id,
+                    customer_name,
+                    priced_order.billing_address,
+                    priced_order.amount_to_bill,
+                )));
+            }
+
+            if let Some(acknowledgment_sent) = acknowledgment_sent {
+                events.push(PlaceOrderEvent::OrderAcknowledgmentSent(acknowledgment_sent));
+            }
+
+            Ok(events)
         }
     }
 
@@ -242,13 +896,111 @@ pub mod implementation {
         use std::sync::Arc;
 
         use crate::public_types::*;
+        use crate::simple_types::Price;
         use anyhow::anyhow;
 
         use super::place_order;
+        use super::price_order;
+        use super::to_checked_address;
+        use super::PricedOrder;
+        use super::ValidatedOrder;
+        use super::ValidatedOrderLine;
+        use crate::simple_types::{EmailAddress, String50};
+        use crate::test_support::fake_address;
+
+        fn fake_customer_info() -> ValidatedCustomerInfo {
+            ValidatedCustomerInfo::new(
+                String50::create("Jane".into()).unwrap(),
+                String50::create("Doe".into()).unwrap(),
+                EmailAddress::create("jane@example.com".into()).unwrap(),
+            )
+        }
+
+        fn fake_unvalidated_address() -> UnvalidatedAddress {
+            UnvalidatedAddress {
+                address_line_1: "1 Fake Street".into(),
+                address_line_2: None,
+                address_line_3: None,
+                address_line_4: None,
+                city: "Faketown".into(),
+                zip_code: "90210".into(),
+            }
+        }
+
+        #[test]
+        fn to_checked_address_propagates_a_failing_address_check() {
+            let check_address_exists =
+                |_address: UnvalidatedAddress| Err(anyhow!("address not found"));
+
+            let result = to_checked_address(check_address_exists, fake_unvalidated_address());
+
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn to_checked_address_accumulates_field_errors_from_a_bad_zip_code() {
+            let check_address_exists = |address: UnvalidatedAddress| {
+                Ok(CheckedAddress {
+                    address_line_1: address.address_line_1,
+                    address_line_2: address.address_line_2,
+                    address_line_3: address.address_line_3,
+                    address_line_4: address.address_line_4,
+                    city: address.city,
+                    zip_code: address.zip_code,
+                })
+            };
+            let mut unvalidated_address = fake_unvalidated_address();
+            unvalidated_address.zip_code = "not-a-zip".into();
+
+            let errors = match to_checked_address(check_address_exists, unvalidated_address) {
+                Err(errors) => errors,
+                Ok(_) => panic!("expected a validation error"),
+            };
+
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].field_name, "zip_code");
+        }
+
+        #[test]
+        fn price_order_sums_line_prices_into_billing_total() {
+            let widget = ProductCode::create("W1234".into()).unwrap();
+            let quantity = OrderQuantity::create(&widget, 5.0).unwrap();
+            let line = ValidatedOrderLine::new(OrderLineId::new("line-1".into()), widget, quantity);
+            let order = ValidatedOrder::new(
+                OrderId::new("order-1".into()),
+                fake_customer_info(),
+                fake_address(),
+                fake_address(),
+                vec![line],
+            );
+
+            let priced_order = price_order(|_code: &ProductCode| Price::create(19.99).unwrap(), order)
+                .unwrap();
+
+            assert!((priced_order.amount_to_bill.value() - 99.95).abs() < 0.001);
+        }
+
+        #[test]
+        fn price_order_rejects_line_price_above_the_cap() {
+            let widget = ProductCode::create("W1234".into()).unwrap();
+            let quantity = OrderQuantity::create(&widget, 1000.0).unwrap();
+            let line = ValidatedOrderLine::new(OrderLineId::new("line-1".into()), widget, quantity);
+            let order = ValidatedOrder::new(
+                OrderId::new("order-1".into()),
+                fake_customer_info(),
+                fake_address(),
+                fake_address(),
+                vec![line],
+            );
+
+            let result = price_order(|_code: &ProductCode| Price::create(1000.0).unwrap(), order);
+
+            assert!(result.is_err());
+        }
 
         #[test]
         fn it_works() {
-            let our_code = ProductCode::new("fake-code".into());
+            let our_code = ProductCode::create("W1234".into()).unwrap();
             let product_ids = Arc::new(vec![our_code]);
 
             let check_product_exists = |code: &ProductCode| {
@@ -257,18 +1009,298 @@ pub mod implementation {
                 }
                 return Err(anyhow!("not found!"));
             };
+            let get_product_price = |_code: &ProductCode| Price::create(19.99).unwrap();
+            let check_address_exists = |address: UnvalidatedAddress| {
+                Ok(CheckedAddress {
+                    address_line_1: address.address_line_1,
+                    address_line_2: address.address_line_2,
+                    address_line_3: address.address_line_3,
+                    address_line_4: address.address_line_4,
+                    city: address.city,
+                    zip_code: address.zip_code,
+                })
+            };
+            let fake_address = || UnvalidatedAddress {
+                address_line_1: "1 Fake Street".into(),
+                address_line_2: None,
+                address_line_3: None,
+                address_line_4: None,
+                city: "Faketown".into(),
+                zip_code: "90210".into(),
+            };
             let unvalidated_order_line = UnvalidatedOrderLine {
                 order_line_id: "fake-line-id".into(),
-                product_code: "fake-code".into(),
-                quantity: 5,
+                product_code: "W1234".into(),
+                quantity: 5.0,
             };
             let unvalidated_order = UnvalidatedOrder {
                 order_id: "some_id".into(),
+                customer_info: UnvalidatedCustomerInfo {
+                    first_name: "Jane".into(),
+                    last_name: "Doe".into(),
+                    email_address: "jane@example.com".into(),
+                },
+                shipping_address: fake_address(),
+                billing_address: fake_address(),
                 lines: vec![unvalidated_order_line],
             };
+            let create_order_acknowledgment_letter =
+                |_order: &PricedOrder| HtmlString::new("<p>Thanks for your order!</p>".into());
+            let send_order_acknowledgment = |_acknowledgment: &OrderAcknowledgment| SendResult::Sent;
 
-            let workflow = place_order(check_product_exists);
+            let workflow = place_order(
+                check_product_exists,
+                check_address_exists,
+                get_product_price,
+                create_order_acknowledgment_letter,
+                send_order_acknowledgment,
+            );
             let result = workflow(unvalidated_order).unwrap();
         }
     }
 }
+
+pub mod event_store {
+    use std::collections::HashMap;
+
+    use anyhow::{anyhow, Result};
+
+    use crate::public_types::{OrderId, PlaceOrderEvent, UnvalidatedOrder, ValidationError};
+
+    pub trait EventStore {
+        fn append(
+            &mut self,
+            order_id: &OrderId,
+            expected_version: i64,
+            events: &[PlaceOrderEvent],
+        ) -> Result<()>;
+
+        fn load(&self, order_id: &OrderId) -> &[PlaceOrderEvent];
+    }
+
+    #[derive(Default)]
+    pub struct InMemoryEventStore {
+        events: HashMap<String, Vec<PlaceOrderEvent>>,
+    }
+
+    impl InMemoryEventStore {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl EventStore for InMemoryEventStore {
+        fn append(
+            &mut self,
+            order_id: &OrderId,
+            expected_version: i64,
+            events: &[PlaceOrderEvent],
+        ) -> Result<()> {
+            let key = order_id.value().to_string();
+            let stream = self.events.entry(key.clone()).or_default();
+            let current_version = stream.len() as i64;
+
+            if current_version != expected_version {
+                return Err(anyhow!(
+                    "expected version {} for order {} but stream is at version {}",
+                    expected_version,
+                    key,
+                    current_version
+                ));
+            }
+
+            stream.extend_from_slice(events);
+            Ok(())
+        }
+
+        fn load(&self, order_id: &OrderId) -> &[PlaceOrderEvent] {
+            self.events
+                .get(order_id.value())
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
+        }
+    }
+
+    pub struct OrderQueryModel {
+        pub order_id: String,
+        pub customer_name: String,
+        pub amount_to_bill: f64,
+        pub version: i64,
+        pub deleted: bool,
+    }
+
+    impl OrderQueryModel {
+        pub fn new(order_id: String) -> Self {
+            Self {
+                order_id,
+                customer_name: String::new(),
+                amount_to_bill: 0.0,
+                version: 0,
+                deleted: false,
+            }
+        }
+    }
+
+    // Folds a single event into the read model. `deleted` is left for an
+    // eventual order-cancellation event, which doesn't exist yet.
+    pub fn project(model: &mut OrderQueryModel, event: &PlaceOrderEvent) {
+        match event {
+            PlaceOrderEvent::OrderPlaced(_) => {}
+            PlaceOrderEvent::BillableOrderPlaced(billable_order_placed) => {
+                model.amount_to_bill = billable_order_placed.amount_to_bill.value();
+                model.customer_name = billable_order_placed.customer_name.clone();
+            }
+            PlaceOrderEvent::OrderAcknowledgmentSent(_) => {}
+        }
+        model.version += 1;
+    }
+
+    // Appends the events produced by a workflow run and folds them into a
+    // fresh read model, so callers get both the events and a queryable
+    // projection without reimplementing the fold themselves.
+    pub fn place_order_and_store(
+        store: &mut impl EventStore,
+        place_order: impl Fn(UnvalidatedOrder) -> Result<Vec<PlaceOrderEvent>, Vec<ValidationError>>,
+        expected_version: i64,
+        unvalidated_order: UnvalidatedOrder,
+    ) -> Result<(Vec<PlaceOrderEvent>, OrderQueryModel), Vec<ValidationError>> {
+        let order_id = OrderId::new(unvalidated_order.order_id.clone());
+        let events = place_order(unvalidated_order)?;
+
+        store
+            .append(&order_id, expected_version, &events)
+            .map_err(|e| vec![ValidationError::new("event_store", e.to_string())])?;
+
+        let mut model = OrderQueryModel::new(order_id.value().to_string());
+        for event in &events {
+            project(&mut model, event);
+        }
+
+        Ok((events, model))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::public_types::{
+            BillableOrderPlaced, OrderAcknowledgmentSent, OrderPlaced, UnvalidatedAddress,
+            UnvalidatedCustomerInfo,
+        };
+        use crate::simple_types::BillingAmount;
+        use crate::test_support::fake_address as fake_billing_address;
+
+        fn fake_unvalidated_order() -> UnvalidatedOrder {
+            UnvalidatedOrder {
+                order_id: "order-1".into(),
+                customer_info: UnvalidatedCustomerInfo {
+                    first_name: "Jane".into(),
+                    last_name: "Doe".into(),
+                    email_address: "jane@example.com".into(),
+                },
+                shipping_address: UnvalidatedAddress {
+                    address_line_1: "1 Fake Street".into(),
+                    address_line_2: None,
+                    address_line_3: None,
+                    address_line_4: None,
+                    city: "Faketown".into(),
+                    zip_code: "90210".into(),
+                },
+                billing_address: UnvalidatedAddress {
+                    address_line_1: "1 Fake Street".into(),
+                    address_line_2: None,
+                    address_line_3: None,
+                    address_line_4: None,
+                    city: "Faketown".into(),
+                    zip_code: "90210".into(),
+                },
+                lines: vec![],
+            }
+        }
+
+        #[test]
+        fn append_accepts_the_expected_version_and_advances_it() {
+            let mut store = InMemoryEventStore::new();
+            let order_id = OrderId::new("order-1".into());
+            let events = vec![PlaceOrderEvent::OrderPlaced(OrderPlaced {})];
+
+            assert!(store.append(&order_id, 0, &events).is_ok());
+            assert_eq!(store.load(&order_id).len(), 1);
+        }
+
+        #[test]
+        fn append_rejects_a_stale_expected_version() {
+            let mut store = InMemoryEventStore::new();
+            let order_id = OrderId::new("order-1".into());
+            let events = vec![PlaceOrderEvent::OrderPlaced(OrderPlaced {})];
+
+            store.append(&order_id, 0, &events).unwrap();
+
+            assert!(store.append(&order_id, 0, &events).is_err());
+        }
+
+        #[test]
+        fn load_returns_an_empty_slice_for_an_unknown_order() {
+            let store = InMemoryEventStore::new();
+            let order_id = OrderId::new("unknown".into());
+
+            assert!(store.load(&order_id).is_empty());
+        }
+
+        #[test]
+        fn project_folds_amount_to_bill_from_a_billable_order_placed_event() {
+            let mut model = OrderQueryModel::new("order-1".into());
+            let event = PlaceOrderEvent::BillableOrderPlaced(BillableOrderPlaced::new(
+                OrderId::new("order-1".into()),
+                "Jane Doe".into(),
+                fake_billing_address(),
+                BillingAmount::create(42.0).unwrap(),
+            ));
+
+            project(&mut model, &event);
+
+            assert_eq!(model.amount_to_bill, 42.0);
+            assert_eq!(model.customer_name, "Jane Doe");
+            assert_eq!(model.version, 1);
+        }
+
+        #[test]
+        fn project_advances_version_without_changing_amount_for_other_events() {
+            let mut model = OrderQueryModel::new("order-1".into());
+            let event = PlaceOrderEvent::OrderAcknowledgmentSent(OrderAcknowledgmentSent::new(
+                OrderId::new("order-1".into()),
+            ));
+
+            project(&mut model, &event);
+
+            assert_eq!(model.amount_to_bill, 0.0);
+            assert_eq!(model.version, 1);
+        }
+
+        #[test]
+        fn place_order_and_store_appends_events_and_returns_a_projected_read_model() {
+            let mut store = InMemoryEventStore::new();
+            let place_order = |unvalidated_order: UnvalidatedOrder| {
+                Ok(vec![
+                    PlaceOrderEvent::OrderPlaced(OrderPlaced {}),
+                    PlaceOrderEvent::BillableOrderPlaced(BillableOrderPlaced::new(
+                        OrderId::new(unvalidated_order.order_id),
+                        "Jane Doe".into(),
+                        fake_billing_address(),
+                        BillingAmount::create(42.0).unwrap(),
+                    )),
+                ])
+            };
+
+            let (events, model) =
+                place_order_and_store(&mut store, place_order, 0, fake_unvalidated_order())
+                    .unwrap();
+
+            assert_eq!(events.len(), 2);
+            assert_eq!(model.order_id, "order-1");
+            assert_eq!(model.customer_name, "Jane Doe");
+            assert_eq!(model.amount_to_bill, 42.0);
+            assert_eq!(model.version, 2);
+            assert_eq!(store.load(&OrderId::new("order-1".into())).len(), 2);
+        }
+    }
+}